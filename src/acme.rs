@@ -0,0 +1,111 @@
+use crate::rrset::RRset;
+use crate::{Client, Error};
+use std::time::{Duration, Instant};
+
+pub struct AcmeClient<'a> {
+    pub(crate) client: &'a crate::Client,
+}
+
+impl<'a> Client {
+    pub fn acme(&'a self) -> AcmeClient<'a> {
+        AcmeClient { client: self }
+    }
+}
+
+const ACME_CHALLENGE_SUBNAME: &str = "_acme-challenge";
+const ACME_CHALLENGE_TYPE: &str = "TXT";
+
+impl<'a> AcmeClient<'a> {
+    pub async fn set_acme_challenge(&self, domain: &str, token_digest: &str) -> Result<(), Error> {
+        let record = format!("\"{token_digest}\"");
+        let ttl = self
+            .client
+            .domain()
+            .get_domain(domain)
+            .await?
+            .minimum_ttl
+            .map(u32::from)
+            .unwrap_or(3600);
+        match self
+            .client
+            .rrset()
+            .get_rrset(domain, ACME_CHALLENGE_SUBNAME, ACME_CHALLENGE_TYPE)
+            .await
+        {
+            Ok(_) => {
+                self.client
+                    .rrset()
+                    .update_rrset(domain, ACME_CHALLENGE_SUBNAME, ACME_CHALLENGE_TYPE, vec![record], ttl)
+                    .await?;
+            }
+            Err(Error::NotFound) => {
+                self.client
+                    .rrset()
+                    .create_rrset(
+                        domain,
+                        &RRset {
+                            subname: Some(ACME_CHALLENGE_SUBNAME.to_string()),
+                            rtype: Some(ACME_CHALLENGE_TYPE.to_string()),
+                            records: Some(vec![record]),
+                            ttl: Some(ttl),
+                            ..Default::default()
+                        },
+                    )
+                    .await?;
+            }
+            Err(error) => return Err(error),
+        }
+        Ok(())
+    }
+
+    pub async fn clear_acme_challenge(&self, domain: &str) -> Result<(), Error> {
+        match self
+            .client
+            .rrset()
+            .delete_rrset(domain, ACME_CHALLENGE_SUBNAME, ACME_CHALLENGE_TYPE)
+            .await
+        {
+            Ok(_) => Ok(()),
+            Err(Error::NotFound) => Ok(()),
+            Err(error) => Err(error),
+        }
+    }
+
+    pub async fn wait_for_propagation(
+        &self,
+        domain: &str,
+        token_digest: &str,
+        timeout: Duration,
+    ) -> Result<(), Error> {
+        let record = format!("\"{token_digest}\"");
+        let domain_info = self.client.domain().get_domain(domain).await?;
+        let poll_interval = Duration::from_secs(domain_info.minimum_ttl.unwrap_or(3600).clamp(1, 30) as u64);
+        let baseline_published = domain_info.published;
+        let started = Instant::now();
+        loop {
+            let rrset = match self
+                .client
+                .rrset()
+                .get_rrset(domain, ACME_CHALLENGE_SUBNAME, ACME_CHALLENGE_TYPE)
+                .await
+            {
+                Ok(rrset) => Some(rrset),
+                Err(Error::NotFound) => None,
+                Err(error) => return Err(error),
+            };
+            if rrset.and_then(|rrset| rrset.records).unwrap_or_default().contains(&record) {
+                // `published` advances only once deSEC has re-signed and pushed the
+                // zone to the authoritative servers, so this confirms the write
+                // above actually propagated rather than just landing in the API.
+                let current = self.client.domain().get_domain(domain).await?;
+                if current.published != baseline_published {
+                    return Ok(());
+                }
+            }
+            if started.elapsed() >= timeout {
+                return Err(Error::Timeout);
+            }
+            tokio::time::sleep(poll_interval).await;
+        }
+    }
+}
@@ -0,0 +1,146 @@
+use crate::rrset::RRset;
+use crate::{Client, Error};
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+
+pub struct DdnsClient<'a> {
+    pub(crate) client: &'a crate::Client,
+    ipv4_endpoints: Vec<String>,
+    ipv6_endpoints: Vec<String>,
+}
+
+impl<'a> Client {
+    pub fn ddns(&'a self) -> DdnsClient<'a> {
+        DdnsClient {
+            client: self,
+            ipv4_endpoints: vec!["https://ipv4.icanhazip.com".to_string()],
+            ipv6_endpoints: vec!["https://ipv6.icanhazip.com".to_string()],
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum UpdatedRecord {
+    A(Ipv4Addr),
+    Aaaa(Ipv6Addr),
+}
+
+impl<'a> DdnsClient<'a> {
+    pub fn with_ipv4_endpoints(mut self, endpoints: Vec<String>) -> Self {
+        self.ipv4_endpoints = endpoints;
+        self
+    }
+
+    pub fn with_ipv6_endpoints(mut self, endpoints: Vec<String>) -> Self {
+        self.ipv6_endpoints = endpoints;
+        self
+    }
+
+    pub async fn update_from_detected_ip(&self, qname: &str) -> Result<Vec<UpdatedRecord>, Error> {
+        let mut updated = Vec::new();
+        if let Some(ip) = detect_ip(&self.ipv4_endpoints).await {
+            updated.extend(self.update_ip(qname, IpAddr::V4(ip)).await?);
+        }
+        if let Some(ip) = detect_ip(&self.ipv6_endpoints).await {
+            updated.extend(self.update_ip(qname, IpAddr::V6(ip)).await?);
+        }
+        Ok(updated)
+    }
+
+    pub async fn update_ip(&self, qname: &str, addr: impl Into<IpAddr>) -> Result<Vec<UpdatedRecord>, Error> {
+        match addr.into() {
+            IpAddr::V4(ip) => self.update_record(qname, "A", ip.to_string(), UpdatedRecord::A(ip)).await,
+            IpAddr::V6(ip) => {
+                self.update_record(qname, "AAAA", ip.to_string(), UpdatedRecord::Aaaa(ip))
+                    .await
+            }
+        }
+    }
+
+    async fn update_record(
+        &self,
+        qname: &str,
+        rtype: &str,
+        record: String,
+        result: UpdatedRecord,
+    ) -> Result<Vec<UpdatedRecord>, Error> {
+        let domain = self.client.domain().get_owning_domain(qname).await?;
+        let domain_name = domain.name.ok_or(Error::NotFound)?;
+        let subname = subname_of(qname, &domain_name);
+        match self.client.rrset().get_rrset(&domain_name, &subname, rtype).await {
+            Ok(rrset) => {
+                if rrset.records.unwrap_or_default() == [record.clone()] {
+                    return Ok(Vec::new());
+                }
+                let ttl = rrset.ttl.unwrap_or(3600);
+                self.client
+                    .rrset()
+                    .update_rrset(&domain_name, &subname, rtype, vec![record], ttl)
+                    .await?;
+            }
+            Err(Error::NotFound) => {
+                let ttl = domain.minimum_ttl.map(u32::from).unwrap_or(3600);
+                self.client
+                    .rrset()
+                    .create_rrset(
+                        &domain_name,
+                        &RRset {
+                            subname: Some(subname),
+                            rtype: Some(rtype.to_string()),
+                            records: Some(vec![record]),
+                            ttl: Some(ttl),
+                            ..Default::default()
+                        },
+                    )
+                    .await?;
+            }
+            Err(error) => return Err(error),
+        }
+        Ok(vec![result])
+    }
+}
+
+fn subname_of(qname: &str, domain_name: &str) -> String {
+    qname
+        .strip_suffix(domain_name)
+        .and_then(|prefix| prefix.strip_suffix('.'))
+        .unwrap_or("")
+        .to_string()
+}
+
+async fn detect_ip<T: std::str::FromStr>(endpoints: &[String]) -> Option<T> {
+    for endpoint in endpoints {
+        if let Ok(response) = reqwest::get(endpoint).await {
+            if let Ok(text) = response.text().await {
+                if let Ok(ip) = text.trim().parse::<T>() {
+                    return Some(ip);
+                }
+            }
+        }
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn subname_of_extracts_prefix() {
+        assert_eq!(subname_of("www.example.com", "example.com"), "www");
+    }
+
+    #[test]
+    fn subname_of_apex_is_empty() {
+        assert_eq!(subname_of("example.com", "example.com"), "");
+    }
+
+    #[test]
+    fn subname_of_nested_subname() {
+        assert_eq!(subname_of("a.b.example.com", "example.com"), "a.b");
+    }
+
+    #[test]
+    fn subname_of_non_matching_suffix_falls_back_to_empty() {
+        assert_eq!(subname_of("www.other.com", "example.com"), "");
+    }
+}
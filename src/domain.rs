@@ -1,4 +1,4 @@
-use crate::{Client, Error};
+use crate::{ApiErrorBody, Client, Error};
 use reqwest::StatusCode;
 use serde::{Deserialize, Serialize};
 
@@ -54,17 +54,41 @@ impl<'a> DomainClient<'a> {
             .post("/domains/", format!("{{\"name\": \"{domain}\"}}"))
             .await
         {
-            Ok(response) if response.status() == StatusCode::OK => response
+            Ok(response) if response.status() == StatusCode::CREATED => response
                 .json()
                 .await
                 .map_err(|error| Error::InvalidAPIResponse(error.to_string())),
             Ok(response) if response.status() == StatusCode::BAD_REQUEST => Err(Error::ApiError(
                 response.status().into(),
-                response.text().await.unwrap_or_default(),
+                ApiErrorBody::from_text(response.text().await.unwrap_or_default()),
             )),
             Ok(response) => Err(Error::UnexpectedStatusCode(
                 response.status().into(),
-                response.text().await.unwrap_or_default(),
+                ApiErrorBody::from_text(response.text().await.unwrap_or_default()),
+            )),
+            Err(error) => Err(Error::Reqwest(error)),
+        }
+    }
+
+    pub async fn create_domain_with_zonefile(&self, domain: String, zonefile: &str) -> Result<Domain, Error> {
+        let body = serde_json::to_string(&Domain {
+            name: Some(domain),
+            zonefile: Some(zonefile.to_string()),
+            ..Default::default()
+        })
+        .map_err(|error| Error::InvalidAPIResponse(error.to_string()))?;
+        match self.client.post("/domains/", body).await {
+            Ok(response) if response.status() == StatusCode::CREATED => response
+                .json()
+                .await
+                .map_err(|error| Error::InvalidAPIResponse(error.to_string())),
+            Ok(response) if response.status() == StatusCode::BAD_REQUEST => Err(Error::ApiError(
+                response.status().into(),
+                ApiErrorBody::from_text(response.text().await.unwrap_or_default()),
+            )),
+            Ok(response) => Err(Error::UnexpectedStatusCode(
+                response.status().into(),
+                ApiErrorBody::from_text(response.text().await.unwrap_or_default()),
             )),
             Err(error) => Err(Error::Reqwest(error)),
         }
@@ -78,7 +102,7 @@ impl<'a> DomainClient<'a> {
                 .map_err(|error| Error::InvalidAPIResponse(error.to_string())),
             Ok(response) => Err(Error::UnexpectedStatusCode(
                 response.status().into(),
-                response.text().await.unwrap_or_default(),
+                ApiErrorBody::from_text(response.text().await.unwrap_or_default()),
             )),
             Err(error) => Err(Error::Reqwest(error)),
         }
@@ -97,7 +121,7 @@ impl<'a> DomainClient<'a> {
             Ok(response) if response.status() == StatusCode::NOT_FOUND => Err(Error::NotFound),
             Ok(response) => Err(Error::UnexpectedStatusCode(
                 response.status().into(),
-                response.text().await.unwrap_or_default(),
+                ApiErrorBody::from_text(response.text().await.unwrap_or_default()),
             )),
             Err(error) => Err(Error::Reqwest(error)),
         }
@@ -115,14 +139,14 @@ impl<'a> DomainClient<'a> {
                 .map_err(|error| Error::InvalidAPIResponse(error.to_string())),
             Ok(response) => Err(Error::UnexpectedStatusCode(
                 response.status().into(),
-                response.text().await.unwrap_or_default(),
+                ApiErrorBody::from_text(response.text().await.unwrap_or_default()),
             )),
             Err(error) => Err(Error::Reqwest(error)),
         }
     }
 
     pub async fn get_owning_domain(&self, qname: &str) -> Result<Domain, Error> {
-        match self
+        let domains: DomainList = match self
             .client
             .get(format!("/domains/?owns_qname={qname}").as_str())
             .await
@@ -130,14 +154,21 @@ impl<'a> DomainClient<'a> {
             Ok(response) if response.status() == StatusCode::OK => response
                 .json()
                 .await
-                .map_err(|error| Error::InvalidAPIResponse(error.to_string())),
-            Ok(response) if response.status() == StatusCode::NOT_FOUND => Err(Error::NotFound),
-            Ok(response) => Err(Error::UnexpectedStatusCode(
-                response.status().into(),
-                response.text().await.unwrap_or_default(),
-            )),
-            Err(error) => Err(Error::Reqwest(error)),
-        }
+                .map_err(|error| Error::InvalidAPIResponse(error.to_string()))?,
+            Ok(response) if response.status() == StatusCode::NOT_FOUND => return Err(Error::NotFound),
+            Ok(response) => {
+                return Err(Error::UnexpectedStatusCode(
+                    response.status().into(),
+                    ApiErrorBody::from_text(response.text().await.unwrap_or_default()),
+                ))
+            }
+            Err(error) => return Err(Error::Reqwest(error)),
+        };
+        domains
+            .into_iter()
+            .filter(|domain| domain.name.is_some())
+            .max_by_key(|domain| domain.name.as_ref().map(String::len).unwrap_or(0))
+            .ok_or(Error::NotFound)
     }
 
     pub async fn get_zonefile(&self, domain: &str) -> Result<String, Error> {
@@ -152,9 +183,79 @@ impl<'a> DomainClient<'a> {
                 .map_err(|error| Error::InvalidAPIResponse(error.to_string())),
             Ok(response) => Err(Error::UnexpectedStatusCode(
                 response.status().into(),
-                response.text().await.unwrap_or_default(),
+                ApiErrorBody::from_text(response.text().await.unwrap_or_default()),
             )),
             Err(error) => Err(Error::Reqwest(error)),
         }
     }
+
+    pub async fn get_ds_records(&self, domain: &str) -> Result<Vec<DsRecord>, Error> {
+        let domain = self.get_domain(domain).await?;
+        Ok(domain
+            .keys
+            .unwrap_or_default()
+            .into_iter()
+            .flat_map(|key| key.ds.unwrap_or_default())
+            .filter_map(|raw| DsRecord::parse(&raw))
+            .collect())
+    }
+
+    pub async fn get_delegation_summary(&self, domain: &str) -> Result<Vec<DNSSECKeyInfo>, Error> {
+        let domain = self.get_domain(domain).await?;
+        Ok(domain
+            .keys
+            .unwrap_or_default()
+            .into_iter()
+            .filter(|key| key.managed.unwrap_or(false))
+            .collect())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DsRecord {
+    pub key_tag: u16,
+    pub algorithm: u8,
+    pub digest_type: u8,
+    pub digest: String,
+}
+
+impl DsRecord {
+    fn parse(raw: &str) -> Option<Self> {
+        let mut parts = raw.splitn(4, ' ');
+        Some(DsRecord {
+            key_tag: parts.next()?.parse().ok()?,
+            algorithm: parts.next()?.parse().ok()?,
+            digest_type: parts.next()?.parse().ok()?,
+            digest: parts.next()?.to_string(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_valid_ds_record() {
+        let record = DsRecord::parse("2371 13 2 1F16FC6BC3C0FCA7FE6EB1A2F1C4C2E8BE45F1F1B4C3F4A4A9C9C9E9E9F9F9F9F").unwrap();
+        assert_eq!(record.key_tag, 2371);
+        assert_eq!(record.algorithm, 13);
+        assert_eq!(record.digest_type, 2);
+        assert_eq!(record.digest, "1F16FC6BC3C0FCA7FE6EB1A2F1C4C2E8BE45F1F1B4C3F4A4A9C9C9E9E9F9F9F9F");
+    }
+
+    #[test]
+    fn parse_rejects_too_few_fields() {
+        assert_eq!(DsRecord::parse("2371 13 2"), None);
+    }
+
+    #[test]
+    fn parse_rejects_non_numeric_key_tag() {
+        assert_eq!(DsRecord::parse("not-a-number 13 2 deadbeef"), None);
+    }
+
+    #[test]
+    fn parse_rejects_empty_input() {
+        assert_eq!(DsRecord::parse(""), None);
+    }
 }
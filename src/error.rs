@@ -0,0 +1,89 @@
+use serde::Deserialize;
+use std::collections::HashMap;
+
+#[derive(Deserialize, Debug, Clone, Default)]
+pub struct DesecApiError {
+    #[serde(default)]
+    pub detail: Option<String>,
+    #[serde(flatten)]
+    pub fields: HashMap<String, Vec<String>>,
+}
+
+#[derive(Debug, Clone)]
+pub enum ApiErrorBody {
+    Structured(DesecApiError),
+    Raw(String),
+}
+
+impl ApiErrorBody {
+    pub fn from_text(text: String) -> Self {
+        match serde_json::from_str(&text) {
+            Ok(error) => ApiErrorBody::Structured(error),
+            Err(_) => ApiErrorBody::Raw(text),
+        }
+    }
+}
+
+impl std::fmt::Display for ApiErrorBody {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ApiErrorBody::Structured(error) => {
+                let mut parts = error.detail.iter().cloned().collect::<Vec<_>>();
+                parts.extend(
+                    error
+                        .fields
+                        .iter()
+                        .map(|(field, messages)| format!("{field}: {}", messages.join(", "))),
+                );
+                write!(f, "{}", parts.join(" "))
+            }
+            ApiErrorBody::Raw(text) => write!(f, "{text}"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn from_text_parses_field_map() {
+        let body = ApiErrorBody::from_text(r#"{"name": ["This field may not be blank."]}"#.to_string());
+        match body {
+            ApiErrorBody::Structured(error) => {
+                assert_eq!(error.detail, None);
+                assert_eq!(error.fields.get("name").unwrap(), &vec!["This field may not be blank.".to_string()]);
+            }
+            ApiErrorBody::Raw(_) => panic!("expected structured body"),
+        }
+    }
+
+    #[test]
+    fn from_text_parses_detail() {
+        let body = ApiErrorBody::from_text(r#"{"detail": "Request was throttled."}"#.to_string());
+        match body {
+            ApiErrorBody::Structured(error) => assert_eq!(error.detail.as_deref(), Some("Request was throttled.")),
+            ApiErrorBody::Raw(_) => panic!("expected structured body"),
+        }
+    }
+
+    #[test]
+    fn from_text_falls_back_to_raw_on_non_json() {
+        let body = ApiErrorBody::from_text("<html>502 Bad Gateway</html>".to_string());
+        assert!(matches!(body, ApiErrorBody::Raw(text) if text == "<html>502 Bad Gateway</html>"));
+    }
+
+    #[test]
+    fn display_has_no_leading_space_when_only_fields_present() {
+        let body = ApiErrorBody::from_text(r#"{"name": ["This field may not be blank."]}"#.to_string());
+        assert_eq!(body.to_string(), "name: This field may not be blank.");
+    }
+
+    #[test]
+    fn display_joins_detail_and_fields() {
+        let body = ApiErrorBody::from_text(
+            r#"{"detail": "Validation failed.", "name": ["This field may not be blank."]}"#.to_string(),
+        );
+        assert_eq!(body.to_string(), "Validation failed. name: This field may not be blank.");
+    }
+}
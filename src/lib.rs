@@ -0,0 +1,96 @@
+use reqwest::{Client as HttpClient, Response};
+
+mod acme;
+mod ddns;
+mod domain;
+mod error;
+mod rrset;
+#[cfg(feature = "trust-dns")]
+mod zonefile;
+
+pub use acme::AcmeClient;
+pub use ddns::{DdnsClient, UpdatedRecord};
+pub use domain::{DNSSECKeyInfo, Domain, DomainClient, DomainList, DsRecord};
+pub use error::{ApiErrorBody, DesecApiError};
+pub use rrset::{RRset, RRsetClient, RRsetList};
+
+const BASE_URL: &str = "https://desec.io/api/v1";
+
+pub struct Client {
+    http: HttpClient,
+    token: String,
+    base_url: String,
+}
+
+impl Client {
+    pub fn new(token: impl Into<String>) -> Self {
+        Client {
+            http: HttpClient::new(),
+            token: token.into(),
+            base_url: BASE_URL.to_string(),
+        }
+    }
+
+    pub(crate) async fn get(&self, path: &str) -> Result<Response, reqwest::Error> {
+        self.http
+            .get(format!("{}{path}", self.base_url))
+            .header("Authorization", format!("Token {}", self.token))
+            .send()
+            .await
+    }
+
+    pub(crate) async fn post(&self, path: &str, body: String) -> Result<Response, reqwest::Error> {
+        self.http
+            .post(format!("{}{path}", self.base_url))
+            .header("Authorization", format!("Token {}", self.token))
+            .header("Content-Type", "application/json")
+            .body(body)
+            .send()
+            .await
+    }
+
+    pub(crate) async fn patch(&self, path: &str, body: String) -> Result<Response, reqwest::Error> {
+        self.http
+            .patch(format!("{}{path}", self.base_url))
+            .header("Authorization", format!("Token {}", self.token))
+            .header("Content-Type", "application/json")
+            .body(body)
+            .send()
+            .await
+    }
+
+    pub(crate) async fn delete(&self, path: &str) -> Result<Response, reqwest::Error> {
+        self.http
+            .delete(format!("{}{path}", self.base_url))
+            .header("Authorization", format!("Token {}", self.token))
+            .send()
+            .await
+    }
+}
+
+#[derive(Debug)]
+pub enum Error {
+    Reqwest(reqwest::Error),
+    ApiError(u16, ApiErrorBody),
+    UnexpectedStatusCode(u16, ApiErrorBody),
+    InvalidAPIResponse(String),
+    NotFound,
+    Timeout,
+}
+
+impl std::fmt::Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Error::Reqwest(error) => write!(f, "request failed: {error}"),
+            Error::ApiError(status, body) => write!(f, "api error ({status}): {body}"),
+            Error::UnexpectedStatusCode(status, body) => {
+                write!(f, "unexpected status code ({status}): {body}")
+            }
+            Error::InvalidAPIResponse(body) => write!(f, "invalid api response: {body}"),
+            Error::NotFound => write!(f, "not found"),
+            Error::Timeout => write!(f, "timed out waiting for propagation"),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
@@ -0,0 +1,154 @@
+use crate::{ApiErrorBody, Client, Error};
+use reqwest::StatusCode;
+use serde::{Deserialize, Serialize};
+
+pub struct RRsetClient<'a> {
+    pub(crate) client: &'a crate::Client,
+}
+
+impl<'a> Client {
+    pub fn rrset(&'a self) -> RRsetClient<'a> {
+        RRsetClient { client: self }
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct RRset {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub created: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub domain: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub subname: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(rename = "type")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub rtype: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub records: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ttl: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub touched: Option<String>,
+}
+
+pub type RRsetList = Vec<RRset>;
+
+impl<'a> RRsetClient<'a> {
+    pub async fn get_rrsets(&self, domain: &str) -> Result<RRsetList, Error> {
+        match self
+            .client
+            .get(format!("/domains/{domain}/rrsets/").as_str())
+            .await
+        {
+            Ok(response) if response.status() == StatusCode::OK => response
+                .json()
+                .await
+                .map_err(|error| Error::InvalidAPIResponse(error.to_string())),
+            Ok(response) => Err(Error::UnexpectedStatusCode(
+                response.status().into(),
+                ApiErrorBody::from_text(response.text().await.unwrap_or_default()),
+            )),
+            Err(error) => Err(Error::Reqwest(error)),
+        }
+    }
+
+    pub async fn get_rrset(&self, domain: &str, subname: &str, rtype: &str) -> Result<RRset, Error> {
+        match self
+            .client
+            .get(format!("/domains/{domain}/rrsets/{subname}/{rtype}/").as_str())
+            .await
+        {
+            Ok(response) if response.status() == StatusCode::OK => response
+                .json()
+                .await
+                .map_err(|error| Error::InvalidAPIResponse(error.to_string())),
+            Ok(response) if response.status() == StatusCode::NOT_FOUND => Err(Error::NotFound),
+            Ok(response) => Err(Error::UnexpectedStatusCode(
+                response.status().into(),
+                ApiErrorBody::from_text(response.text().await.unwrap_or_default()),
+            )),
+            Err(error) => Err(Error::Reqwest(error)),
+        }
+    }
+
+    pub async fn create_rrset(&self, domain: &str, rrset: &RRset) -> Result<RRset, Error> {
+        let body = serde_json::to_string(rrset).map_err(|error| Error::InvalidAPIResponse(error.to_string()))?;
+        match self
+            .client
+            .post(format!("/domains/{domain}/rrsets/").as_str(), body)
+            .await
+        {
+            Ok(response) if response.status() == StatusCode::CREATED => response
+                .json()
+                .await
+                .map_err(|error| Error::InvalidAPIResponse(error.to_string())),
+            Ok(response) if response.status() == StatusCode::BAD_REQUEST => Err(Error::ApiError(
+                response.status().into(),
+                ApiErrorBody::from_text(response.text().await.unwrap_or_default()),
+            )),
+            Ok(response) => Err(Error::UnexpectedStatusCode(
+                response.status().into(),
+                ApiErrorBody::from_text(response.text().await.unwrap_or_default()),
+            )),
+            Err(error) => Err(Error::Reqwest(error)),
+        }
+    }
+
+    pub async fn update_rrset(
+        &self,
+        domain: &str,
+        subname: &str,
+        rtype: &str,
+        records: Vec<String>,
+        ttl: u32,
+    ) -> Result<RRset, Error> {
+        let body = serde_json::to_string(&RRset {
+            records: Some(records),
+            ttl: Some(ttl),
+            ..Default::default()
+        })
+        .map_err(|error| Error::InvalidAPIResponse(error.to_string()))?;
+        match self
+            .client
+            .patch(
+                format!("/domains/{domain}/rrsets/{subname}/{rtype}/").as_str(),
+                body,
+            )
+            .await
+        {
+            Ok(response) if response.status() == StatusCode::OK => response
+                .json()
+                .await
+                .map_err(|error| Error::InvalidAPIResponse(error.to_string())),
+            Ok(response) if response.status() == StatusCode::BAD_REQUEST => Err(Error::ApiError(
+                response.status().into(),
+                ApiErrorBody::from_text(response.text().await.unwrap_or_default()),
+            )),
+            Ok(response) => Err(Error::UnexpectedStatusCode(
+                response.status().into(),
+                ApiErrorBody::from_text(response.text().await.unwrap_or_default()),
+            )),
+            Err(error) => Err(Error::Reqwest(error)),
+        }
+    }
+
+    pub async fn delete_rrset(&self, domain: &str, subname: &str, rtype: &str) -> Result<String, Error> {
+        match self
+            .client
+            .delete(format!("/domains/{domain}/rrsets/{subname}/{rtype}/").as_str())
+            .await
+        {
+            Ok(response) if response.status() == StatusCode::NO_CONTENT => response
+                .text()
+                .await
+                .map_err(|error| Error::InvalidAPIResponse(error.to_string())),
+            Ok(response) => Err(Error::UnexpectedStatusCode(
+                response.status().into(),
+                ApiErrorBody::from_text(response.text().await.unwrap_or_default()),
+            )),
+            Err(error) => Err(Error::Reqwest(error)),
+        }
+    }
+}
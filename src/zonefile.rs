@@ -0,0 +1,18 @@
+#![cfg(feature = "trust-dns")]
+
+use crate::{DomainClient, Error};
+use trust_dns_client::rr::Record;
+use trust_dns_client::serialize::txt::{Lexer, Parser};
+
+impl<'a> DomainClient<'a> {
+    pub async fn get_zonefile_parsed(&self, domain: &str) -> Result<Vec<Record>, Error> {
+        let zonefile = self.get_zonefile(domain).await?;
+        let (_origin, records) = Parser::new()
+            .parse(Lexer::new(&zonefile), None, None)
+            .map_err(|error| Error::InvalidAPIResponse(error.to_string()))?;
+        Ok(records
+            .into_values()
+            .flat_map(|record_set| record_set.records_without_rrsigs().cloned().collect::<Vec<_>>())
+            .collect())
+    }
+}